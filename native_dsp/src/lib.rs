@@ -1,7 +1,27 @@
 use pyo3::prelude::*;
-use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::PyValueError;
+use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2};
+use numpy::ndarray::{Array2, Axis};
+use num_complex::Complex32;
+use rayon::prelude::*;
 use std::f32::consts::PI;
 
+/// Longest feedforward/feedback coefficient array `IIRFilter` will accept.
+const MAX_IIR_ORDER: usize = 20;
+
+/// RBJ "Cookbook" biquad topologies that `BiquadFilter::set_coefficients` can realize.
+#[derive(Clone, Copy, PartialEq)]
+enum FilterType {
+    LowPass,
+    HighPass,
+    BandPass,
+    Notch,
+    AllPass,
+    LowShelf,
+    HighShelf,
+    Peaking,
+}
+
 #[derive(Clone)]
 struct BiquadFilter {
     b0: f32, b1: f32, b2: f32,
@@ -20,21 +40,70 @@ impl BiquadFilter {
         }
     }
 
-    fn set_peaking_eq(&mut self, freq: f32, q: f32, gain_db: f32, sample_rate: f32) {
-        let omega = 2.0 * PI * freq / sample_rate;
-        let alpha = omega.sin() / (2.0 * q);
+    /// Realize any of the RBJ cookbook biquad types from a center/cutoff frequency,
+    /// Q, gain (used only by the shelving and peaking types) and sample rate.
+    fn set_coefficients(&mut self, filter_type: FilterType, freq: f32, q: f32, gain_db: f32, sample_rate: f32) {
+        let w0 = 2.0 * PI * freq / sample_rate;
+        let cos_w0 = w0.cos();
+        let sin_w0 = w0.sin();
+        let alpha = sin_w0 / (2.0 * q);
         let a = 10.0f32.powf(gain_db / 40.0);
-        
-        let cos_w = omega.cos();
-        let alpha_a = alpha * a;
-        let alpha_div_a = alpha / a;
 
-        let b0 = 1.0 + alpha_a;
-        let b1 = -2.0 * cos_w;
-        let b2 = 1.0 - alpha_a;
-        let a0 = 1.0 + alpha_div_a;
-        let a1 = -2.0 * cos_w;
-        let a2 = 1.0 - alpha_div_a;
+        let (b0, b1, b2, a0, a1, a2) = match filter_type {
+            FilterType::LowPass => {
+                let b1 = 1.0 - cos_w0;
+                let b0 = b1 / 2.0;
+                (b0, b1, b0, 1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha)
+            }
+            FilterType::HighPass => {
+                let b1 = -(1.0 + cos_w0);
+                let b0 = (1.0 + cos_w0) / 2.0;
+                (b0, b1, b0, 1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha)
+            }
+            FilterType::BandPass => {
+                (alpha, 0.0, -alpha, 1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha)
+            }
+            FilterType::Notch => {
+                (1.0, -2.0 * cos_w0, 1.0, 1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha)
+            }
+            FilterType::AllPass => {
+                (1.0 - alpha, -2.0 * cos_w0, 1.0 + alpha, 1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha)
+            }
+            FilterType::LowShelf => {
+                let sqrt_a_alpha2 = 2.0 * a.sqrt() * alpha;
+                (
+                    a * ((a + 1.0) - (a - 1.0) * cos_w0 + sqrt_a_alpha2),
+                    2.0 * a * ((a - 1.0) - (a + 1.0) * cos_w0),
+                    a * ((a + 1.0) - (a - 1.0) * cos_w0 - sqrt_a_alpha2),
+                    (a + 1.0) + (a - 1.0) * cos_w0 + sqrt_a_alpha2,
+                    -2.0 * ((a - 1.0) + (a + 1.0) * cos_w0),
+                    (a + 1.0) + (a - 1.0) * cos_w0 - sqrt_a_alpha2,
+                )
+            }
+            FilterType::HighShelf => {
+                let sqrt_a_alpha2 = 2.0 * a.sqrt() * alpha;
+                (
+                    a * ((a + 1.0) + (a - 1.0) * cos_w0 + sqrt_a_alpha2),
+                    -2.0 * a * ((a - 1.0) + (a + 1.0) * cos_w0),
+                    a * ((a + 1.0) + (a - 1.0) * cos_w0 - sqrt_a_alpha2),
+                    (a + 1.0) - (a - 1.0) * cos_w0 + sqrt_a_alpha2,
+                    2.0 * ((a - 1.0) - (a + 1.0) * cos_w0),
+                    (a + 1.0) - (a - 1.0) * cos_w0 - sqrt_a_alpha2,
+                )
+            }
+            FilterType::Peaking => {
+                let alpha_a = alpha * a;
+                let alpha_div_a = alpha / a;
+                (
+                    1.0 + alpha_a,
+                    -2.0 * cos_w0,
+                    1.0 - alpha_a,
+                    1.0 + alpha_div_a,
+                    -2.0 * cos_w0,
+                    1.0 - alpha_div_a,
+                )
+            }
+        };
 
         self.b0 = b0 / a0;
         self.b1 = b1 / a0;
@@ -43,17 +112,116 @@ impl BiquadFilter {
         self.a2 = a2 / a0;
     }
 
+    fn set_peaking_eq(&mut self, freq: f32, q: f32, gain_db: f32, sample_rate: f32) {
+        self.set_coefficients(FilterType::Peaking, freq, q, gain_db, sample_rate);
+    }
+
     fn process(&mut self, input: f32) -> f32 {
         let output = self.b0 * input + self.b1 * self.x1 + self.b2 * self.x2
                     - self.a1 * self.y1 - self.a2 * self.y2;
-        
+
         self.x2 = self.x1;
         self.x1 = input;
         self.y2 = self.y1;
         self.y1 = output;
-        
+
         output
     }
+
+    /// Evaluate `H(z)` at `z_inv = z^-1` for this section's normalized coefficients.
+    fn response_at(&self, z_inv: Complex32) -> Complex32 {
+        let num = Complex32::new(self.b0, 0.0) + z_inv * self.b1 + z_inv * z_inv * self.b2;
+        let den = Complex32::new(1.0, 0.0) + z_inv * self.a1 + z_inv * z_inv * self.a2;
+        num / den
+    }
+
+    /// Clear the delay line without touching the coefficients.
+    fn reset_state(&mut self) {
+        self.x1 = 0.0;
+        self.x2 = 0.0;
+        self.y1 = 0.0;
+        self.y2 = 0.0;
+    }
+}
+
+/// A single RBJ cookbook biquad, selectable by type, for building crossovers,
+/// tone controls and rumble filters directly from Python rather than via the
+/// fixed peaking-band `Equalizer` topology.
+#[pyclass]
+struct Filter {
+    filter: BiquadFilter,
+    sample_rate: f32,
+}
+
+#[pymethods]
+impl Filter {
+    #[new]
+    fn new(sample_rate: f32) -> Self {
+        Filter { filter: BiquadFilter::new(), sample_rate }
+    }
+
+    fn set_lowpass(&mut self, freq: f32, q: f32) {
+        self.filter.set_coefficients(FilterType::LowPass, freq, q, 0.0, self.sample_rate);
+    }
+
+    fn set_highpass(&mut self, freq: f32, q: f32) {
+        self.filter.set_coefficients(FilterType::HighPass, freq, q, 0.0, self.sample_rate);
+    }
+
+    fn set_bandpass(&mut self, freq: f32, q: f32) {
+        self.filter.set_coefficients(FilterType::BandPass, freq, q, 0.0, self.sample_rate);
+    }
+
+    fn set_notch(&mut self, freq: f32, q: f32) {
+        self.filter.set_coefficients(FilterType::Notch, freq, q, 0.0, self.sample_rate);
+    }
+
+    fn set_allpass(&mut self, freq: f32, q: f32) {
+        self.filter.set_coefficients(FilterType::AllPass, freq, q, 0.0, self.sample_rate);
+    }
+
+    fn set_lowshelf(&mut self, freq: f32, q: f32, gain_db: f32) {
+        self.filter.set_coefficients(FilterType::LowShelf, freq, q, gain_db, self.sample_rate);
+    }
+
+    fn set_highshelf(&mut self, freq: f32, q: f32, gain_db: f32) {
+        self.filter.set_coefficients(FilterType::HighShelf, freq, q, gain_db, self.sample_rate);
+    }
+
+    fn set_peaking(&mut self, freq: f32, q: f32, gain_db: f32) {
+        self.filter.set_coefficients(FilterType::Peaking, freq, q, gain_db, self.sample_rate);
+    }
+
+    fn process_audio(&mut self, py: Python<'_>, input: PyReadonlyArray1<f32>) -> PyResult<Py<PyArray1<f32>>> {
+        let data = input.as_slice().unwrap();
+        let output: Vec<f32> = data.iter().map(|&sample| self.filter.process(sample)).collect();
+        let array = PyArray1::<f32>::from_slice_bound(py, &output);
+        Ok(array.into())
+    }
+
+    fn reset(&mut self) {
+        self.filter.reset_state();
+    }
+}
+
+/// Bilinear-transform an analog second-order section `(c2*s^2+c1*s+c0)/(d2*s^2+d1*s+d0)`
+/// into a normalized digital biquad, substituting `s = k*(1-z^-1)/(1+z^-1)` with `k = 2*fs`.
+/// Sections of lower order are expressed by zeroing the unused high-order terms.
+fn bilinear_biquad(c2: f32, c1: f32, c0: f32, d2: f32, d1: f32, d0: f32, k: f32) -> BiquadFilter {
+    let k2 = k * k;
+
+    let b0 = c2 * k2 + c1 * k + c0;
+    let b1 = 2.0 * c0 - 2.0 * c2 * k2;
+    let b2 = c2 * k2 - c1 * k + c0;
+    let a0 = d2 * k2 + d1 * k + d0;
+    let a1 = 2.0 * d0 - 2.0 * d2 * k2;
+    let a2 = d2 * k2 - d1 * k + d0;
+
+    BiquadFilter {
+        b0: b0 / a0, b1: b1 / a0, b2: b2 / a0,
+        a1: a1 / a0, a2: a2 / a0,
+        x1: 0.0, x2: 0.0, y1: 0.0, y2: 0.0,
+    }
 }
 
 #[pyclass]
@@ -85,18 +253,67 @@ impl Equalizer {
         }
     }
 
-    fn set_gains(&mut self, gains: Vec<f32>) {
-        if gains.len() == self.gains.len() {
-            self.gains = gains;
-            for i in 0..self.filters.len() {
-                self.filters[i].set_peaking_eq(
-                    self.frequencies[i],
-                    self.q_values[i],
-                    self.gains[i],
-                    self.sample_rate
-                );
+    /// Build an `Equalizer` with arbitrary band frequencies and per-band Q
+    /// (e.g. a 31-band third-octave analyzer or a parametric EQ).
+    #[staticmethod]
+    fn from_bands(frequencies: Vec<f32>, q_values: Vec<f32>, sample_rate: f32) -> PyResult<Self> {
+        if frequencies.len() != q_values.len() {
+            return Err(PyValueError::new_err("frequencies and q_values must have the same length"));
+        }
+        let num_bands = frequencies.len();
+
+        let mut eq = Equalizer {
+            filters: vec![BiquadFilter::new(); num_bands],
+            sample_rate,
+            frequencies,
+            gains: vec![0.0; num_bands],
+            q_values,
+        };
+        for i in 0..num_bands {
+            eq.rebuild_band(i);
+        }
+        Ok(eq)
+    }
+
+    fn set_gains(&mut self, gains: Vec<f32>) -> PyResult<()> {
+        if gains.len() != self.gains.len() {
+            return Err(PyValueError::new_err("gains must match the current band count"));
+        }
+        for i in 0..gains.len() {
+            if gains[i] != self.gains[i] {
+                self.gains[i] = gains[i];
+                self.rebuild_band(i);
             }
         }
+        Ok(())
+    }
+
+    /// Replace the band center frequencies, rebuilding only the bands that changed.
+    fn set_frequencies(&mut self, frequencies: Vec<f32>) -> PyResult<()> {
+        if frequencies.len() != self.frequencies.len() {
+            return Err(PyValueError::new_err("frequencies must match the current band count"));
+        }
+        for i in 0..frequencies.len() {
+            if frequencies[i] != self.frequencies[i] {
+                self.frequencies[i] = frequencies[i];
+                self.rebuild_band(i);
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the per-band Q, rebuilding only the bands that changed.
+    fn set_q_values(&mut self, q_values: Vec<f32>) -> PyResult<()> {
+        if q_values.len() != self.q_values.len() {
+            return Err(PyValueError::new_err("q_values must match the current band count"));
+        }
+        for i in 0..q_values.len() {
+            if q_values[i] != self.q_values[i] {
+                self.q_values[i] = q_values[i];
+                self.rebuild_band(i);
+            }
+        }
+        Ok(())
     }
 
     fn process_audio(&mut self, py: Python<'_>, input: PyReadonlyArray1<f32>) -> PyResult<Py<PyArray1<f32>>> {
@@ -116,15 +333,471 @@ impl Equalizer {
     }
 
     fn reset(&mut self) {
-        for filter in &mut self.filters {
-            *filter = BiquadFilter::new();
+        self.gains = vec![0.0; self.gains.len()];
+        for i in 0..self.filters.len() {
+            self.rebuild_band(i);
+            self.filters[i].reset_state();
+        }
+    }
+
+    /// Magnitude (dB) and phase (radians) of the cascaded response at each requested frequency.
+    fn frequency_response(&self, py: Python<'_>, freqs: PyReadonlyArray1<f32>) -> PyResult<(Py<PyArray1<f32>>, Py<PyArray1<f32>>)> {
+        let freqs = freqs.as_slice().unwrap();
+        let mut magnitude_db = Vec::with_capacity(freqs.len());
+        let mut phase = Vec::with_capacity(freqs.len());
+
+        for &freq in freqs {
+            let omega = 2.0 * PI * freq / self.sample_rate;
+            let z_inv = Complex32::new(omega.cos(), -omega.sin());
+
+            let mut h = Complex32::new(1.0, 0.0);
+            for filter in &self.filters {
+                h *= filter.response_at(z_inv);
+            }
+
+            magnitude_db.push(20.0 * h.norm().log10());
+            phase.push(h.arg());
+        }
+
+        let magnitude_db = PyArray1::<f32>::from_slice_bound(py, &magnitude_db);
+        let phase = PyArray1::<f32>::from_slice_bound(py, &phase);
+        Ok((magnitude_db.into(), phase.into()))
+    }
+}
+
+impl Equalizer {
+    fn rebuild_band(&mut self, i: usize) {
+        self.filters[i].set_peaking_eq(
+            self.frequencies[i],
+            self.q_values[i],
+            self.gains[i],
+            self.sample_rate,
+        );
+    }
+}
+
+/// Arbitrary-order direct-form I IIR filter driven by explicit feedforward (`b`)
+/// and feedback (`a`) coefficient arrays, for designs (elliptic, Chebyshev,
+/// measurement filters) that don't reduce to a single peaking biquad.
+#[pyclass]
+struct IIRFilter {
+    b: Vec<f32>,
+    a: Vec<f32>,
+    x_history: Vec<f32>,
+    y_history: Vec<f32>,
+}
+
+#[pymethods]
+impl IIRFilter {
+    #[new]
+    fn new(b: Vec<f32>, a: Vec<f32>) -> PyResult<Self> {
+        if b.is_empty() || a.is_empty() {
+            return Err(PyValueError::new_err("b and a must not be empty"));
+        }
+        if b.len() > MAX_IIR_ORDER || a.len() > MAX_IIR_ORDER {
+            return Err(PyValueError::new_err(format!(
+                "b and a must not exceed length {}",
+                MAX_IIR_ORDER
+            )));
+        }
+        if b.iter().all(|&v| v == 0.0) {
+            return Err(PyValueError::new_err("b must not be all-zero"));
+        }
+        if a[0] == 0.0 {
+            return Err(PyValueError::new_err("a[0] must not be zero"));
+        }
+
+        let a0 = a[0];
+        let b: Vec<f32> = b.iter().map(|&v| v / a0).collect();
+        let a: Vec<f32> = a.iter().map(|&v| v / a0).collect();
+
+        Ok(IIRFilter {
+            x_history: vec![0.0; b.len()],
+            y_history: vec![0.0; a.len() - 1],
+            b,
+            a,
+        })
+    }
+
+    fn process_audio(&mut self, py: Python<'_>, input: PyReadonlyArray1<f32>) -> PyResult<Py<PyArray1<f32>>> {
+        let data = input.as_slice().unwrap();
+        let mut output = Vec::with_capacity(data.len());
+
+        for &sample in data {
+            for i in (1..self.x_history.len()).rev() {
+                self.x_history[i] = self.x_history[i - 1];
+            }
+            self.x_history[0] = sample;
+
+            let mut y = 0.0;
+            for (k, &bk) in self.b.iter().enumerate() {
+                y += bk * self.x_history[k];
+            }
+            for (k, &ak) in self.a.iter().enumerate().skip(1) {
+                y -= ak * self.y_history[k - 1];
+            }
+
+            for i in (1..self.y_history.len()).rev() {
+                self.y_history[i] = self.y_history[i - 1];
+            }
+            if let Some(slot) = self.y_history.first_mut() {
+                *slot = y;
+            }
+
+            output.push(y);
+        }
+
+        let array = PyArray1::<f32>::from_slice_bound(py, &output);
+        Ok(array.into())
+    }
+
+    fn reset(&mut self) {
+        self.x_history.iter_mut().for_each(|v| *v = 0.0);
+        self.y_history.iter_mut().for_each(|v| *v = 0.0);
+    }
+}
+
+/// One independent biquad cascade per channel, processed in parallel across
+/// channels via rayon so an N-channel buffer costs roughly one channel's worth
+/// of wall-clock time instead of looping per-channel in Python.
+#[pyclass]
+struct BiquadBank {
+    channels: Vec<Vec<BiquadFilter>>,
+    sample_rate: f32,
+}
+
+#[pymethods]
+impl BiquadBank {
+    #[new]
+    fn new(num_channels: usize, num_bands: usize, sample_rate: f32) -> Self {
+        BiquadBank {
+            channels: vec![vec![BiquadFilter::new(); num_bands]; num_channels],
+            sample_rate,
+        }
+    }
+
+    /// Set band `band` to the same peaking-EQ coefficients on every channel.
+    fn set_band(&mut self, band: usize, freq: f32, q: f32, gain_db: f32) -> PyResult<()> {
+        for cascade in &mut self.channels {
+            let filter = cascade
+                .get_mut(band)
+                .ok_or_else(|| PyValueError::new_err("band index out of range"))?;
+            filter.set_peaking_eq(freq, q, gain_db, self.sample_rate);
+        }
+        Ok(())
+    }
+
+    /// Set band `band` on a single channel, for per-channel coefficients.
+    fn set_channel_band(&mut self, channel: usize, band: usize, freq: f32, q: f32, gain_db: f32) -> PyResult<()> {
+        let cascade = self
+            .channels
+            .get_mut(channel)
+            .ok_or_else(|| PyValueError::new_err("channel index out of range"))?;
+        let filter = cascade
+            .get_mut(band)
+            .ok_or_else(|| PyValueError::new_err("band index out of range"))?;
+        filter.set_peaking_eq(freq, q, gain_db, self.sample_rate);
+        Ok(())
+    }
+
+    fn process_audio(&mut self, py: Python<'_>, input: PyReadonlyArray2<f32>) -> PyResult<Py<PyArray2<f32>>> {
+        let data = input.as_array();
+        let num_channels = data.shape()[0];
+        let num_samples = data.shape()[1];
+
+        if num_channels != self.channels.len() {
+            return Err(PyValueError::new_err(format!(
+                "expected {} channels, got {}",
+                self.channels.len(),
+                num_channels
+            )));
+        }
+
+        let mut output = Array2::<f32>::zeros((num_channels, num_samples));
+
+        self.channels
+            .par_iter_mut()
+            .zip(output.axis_iter_mut(Axis(0)).into_par_iter())
+            .enumerate()
+            .for_each(|(ch, (cascade, mut out_row))| {
+                let in_row = data.row(ch);
+                for (i, &sample) in in_row.iter().enumerate() {
+                    let mut processed = sample;
+                    for filter in cascade.iter_mut() {
+                        processed = filter.process(processed);
+                    }
+                    out_row[i] = processed;
+                }
+            });
+
+        Ok(PyArray2::from_array_bound(py, &output).into())
+    }
+
+    fn reset(&mut self) {
+        for cascade in &mut self.channels {
+            for filter in cascade.iter_mut() {
+                filter.reset_state();
+            }
+        }
+    }
+}
+
+/// Standards-based frequency weighting (IEC 61672 A/C curves), realized as a
+/// cascade of biquads via [`bilinear_biquad`] so it reuses the same per-sample
+/// state machine as `BiquadFilter`/`Equalizer`.
+#[pyclass]
+struct WeightingFilter {
+    sections: Vec<BiquadFilter>,
+}
+
+#[pymethods]
+impl WeightingFilter {
+    /// `weighting` is `"A"` or `"C"`.
+    #[new]
+    fn new(weighting: &str, sample_rate: f32) -> PyResult<Self> {
+        // IEC 61672 pole frequencies (Hz), converted to rad/s.
+        let p1 = 2.0 * PI * 20.6;
+        let p2 = 2.0 * PI * 107.7;
+        let p3 = 2.0 * PI * 737.9;
+        let p4 = 2.0 * PI * 12194.0;
+        let k = 2.0 * sample_rate;
+
+        let mut sections = match weighting.to_ascii_uppercase().as_str() {
+            "A" => vec![
+                bilinear_biquad(1.0, 0.0, 0.0, 1.0, 2.0 * p1, p1 * p1, k),
+                bilinear_biquad(1.0, 0.0, 0.0, 1.0, 2.0 * p4, p4 * p4, k),
+                bilinear_biquad(0.0, 0.0, 1.0, 0.0, 1.0, p2, k),
+                bilinear_biquad(0.0, 0.0, 1.0, 0.0, 1.0, p3, k),
+            ],
+            "C" => vec![
+                bilinear_biquad(1.0, 0.0, 0.0, 1.0, 2.0 * p1, p1 * p1, k),
+                bilinear_biquad(0.0, 0.0, 1.0, 1.0, 2.0 * p4, p4 * p4, k),
+            ],
+            _ => return Err(PyValueError::new_err("weighting must be \"A\" or \"C\"")),
+        };
+
+        // Normalize the cascade to 0 dB at 1 kHz.
+        let omega = 2.0 * PI * 1000.0 / sample_rate;
+        let z_inv = Complex32::new(omega.cos(), -omega.sin());
+        let mut h = Complex32::new(1.0, 0.0);
+        for section in &sections {
+            h *= section.response_at(z_inv);
+        }
+        let gain = 1.0 / h.norm();
+        sections[0].b0 *= gain;
+        sections[0].b1 *= gain;
+        sections[0].b2 *= gain;
+
+        Ok(WeightingFilter { sections })
+    }
+
+    fn process_audio(&mut self, py: Python<'_>, input: PyReadonlyArray1<f32>) -> PyResult<Py<PyArray1<f32>>> {
+        let data = input.as_slice().unwrap();
+        let mut output = Vec::with_capacity(data.len());
+
+        for &sample in data {
+            let mut processed = sample;
+            for section in &mut self.sections {
+                processed = section.process(processed);
+            }
+            output.push(processed);
+        }
+
+        let array = PyArray1::<f32>::from_slice_bound(py, &output);
+        Ok(array.into())
+    }
+
+    fn reset(&mut self) {
+        for section in &mut self.sections {
+            section.reset_state();
+        }
+    }
+}
+
+/// Sound-level-meter statistics (Leq, Lmax, percentile levels) computed from a
+/// (typically already weighted) signal, in dB relative to `reference`.
+#[pyclass]
+struct Spl {
+    reference: f32,
+    window_samples: usize,
+}
+
+#[pymethods]
+impl Spl {
+    #[new]
+    fn new(sample_rate: f32, window_ms: f32, reference: f32) -> Self {
+        let window_samples = ((sample_rate * window_ms / 1000.0).round() as usize).max(1);
+        Spl { reference, window_samples }
+    }
+
+    /// RMS level (dB re `reference`) of each successive analysis window.
+    fn levels(&self, py: Python<'_>, signal: PyReadonlyArray1<f32>) -> PyResult<Py<PyArray1<f32>>> {
+        let data = signal.as_slice().unwrap();
+        let levels: Vec<f32> = data
+            .chunks(self.window_samples)
+            .map(|chunk| self.level_db(chunk))
+            .collect();
+        Ok(PyArray1::from_slice_bound(py, &levels).into())
+    }
+
+    /// Energy-averaged level (Leq) over the whole signal.
+    fn leq(&self, signal: PyReadonlyArray1<f32>) -> f32 {
+        self.level_db(signal.as_slice().unwrap())
+    }
+
+    /// Maximum windowed level (Lmax).
+    fn lmax(&self, signal: PyReadonlyArray1<f32>) -> f32 {
+        signal
+            .as_slice()
+            .unwrap()
+            .chunks(self.window_samples)
+            .map(|chunk| self.level_db(chunk))
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Interpolated percentile level (e.g. `percent=10` for L10) from the
+    /// sorted per-window levels: the level exceeded for `percent`% of the time.
+    fn percentile(&self, signal: PyReadonlyArray1<f32>, percent: f32) -> f32 {
+        let mut levels: Vec<f32> = signal
+            .as_slice()
+            .unwrap()
+            .chunks(self.window_samples)
+            .map(|chunk| self.level_db(chunk))
+            .collect();
+        if levels.is_empty() {
+            return f32::NAN;
+        }
+        levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let rank = (1.0 - percent / 100.0) * (levels.len() - 1) as f32;
+        let lo = rank.floor() as usize;
+        let hi = rank.ceil() as usize;
+        let frac = rank - lo as f32;
+        levels[lo] * (1.0 - frac) + levels[hi] * frac
+    }
+}
+
+impl Spl {
+    fn level_db(&self, chunk: &[f32]) -> f32 {
+        let mean_sq = chunk.iter().map(|&s| s * s).sum::<f32>() / chunk.len() as f32;
+        20.0 * (mean_sq.sqrt() / self.reference).max(1e-12).log10()
+    }
+}
+
+/// Prewarped 2nd-order Butterworth lowpass at cutoff `fc`.
+fn butterworth_lowpass(fc: f32, sample_rate: f32) -> BiquadFilter {
+    let f = (PI * fc / sample_rate).tan();
+    let a0r = 1.0 / (1.0 + std::f32::consts::SQRT_2 * f + f * f);
+
+    let b0 = f * f * a0r;
+    BiquadFilter {
+        b0, b1: 2.0 * b0, b2: b0,
+        a1: (2.0 * f * f - 2.0) * a0r,
+        a2: (1.0 - std::f32::consts::SQRT_2 * f + f * f) * a0r,
+        x1: 0.0, x2: 0.0, y1: 0.0, y2: 0.0,
+    }
+}
+
+/// Prewarped 2nd-order Butterworth highpass at cutoff `fc`.
+fn butterworth_highpass(fc: f32, sample_rate: f32) -> BiquadFilter {
+    let f = (PI * fc / sample_rate).tan();
+    let a0r = 1.0 / (1.0 + std::f32::consts::SQRT_2 * f + f * f);
+
+    BiquadFilter {
+        b0: a0r, b1: -2.0 * a0r, b2: a0r,
+        a1: (2.0 * f * f - 2.0) * a0r,
+        a2: (1.0 - std::f32::consts::SQRT_2 * f + f * f) * a0r,
+        x1: 0.0, x2: 0.0, y1: 0.0, y2: 0.0,
+    }
+}
+
+/// A 4th-order Linkwitz-Riley lowpass/highpass is two cascaded 2nd-order
+/// Butterworth sections at the same cutoff, so lowpass+highpass sum flat.
+fn linkwitz_riley_lowpass(fc: f32, sample_rate: f32) -> [BiquadFilter; 2] {
+    [butterworth_lowpass(fc, sample_rate), butterworth_lowpass(fc, sample_rate)]
+}
+
+fn linkwitz_riley_highpass(fc: f32, sample_rate: f32) -> [BiquadFilter; 2] {
+    [butterworth_highpass(fc, sample_rate), butterworth_highpass(fc, sample_rate)]
+}
+
+/// Splits a signal into N+1 frequency bands for multiband compression or
+/// 2/3-way speaker simulation, using Linkwitz-Riley (LR4) crossovers. With a
+/// single crossover frequency the two bands sum to a flat magnitude response.
+/// With more, each middle band is the LR4 highpass of the crossover below
+/// cascaded with the LR4 lowpass of the crossover above, the top band is only
+/// the highest crossover's highpass, and no allpass phase compensation is
+/// applied, so the band sum is not exactly flat.
+#[pyclass]
+struct Crossover {
+    bands: Vec<Vec<BiquadFilter>>,
+}
+
+#[pymethods]
+impl Crossover {
+    /// `frequencies` are the crossover points, ascending, producing `frequencies.len() + 1` bands.
+    #[new]
+    fn new(frequencies: Vec<f32>, sample_rate: f32) -> PyResult<Self> {
+        if frequencies.is_empty() {
+            return Err(PyValueError::new_err("frequencies must not be empty"));
+        }
+        if frequencies.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(PyValueError::new_err("frequencies must be strictly ascending"));
+        }
+
+        let mut bands = Vec::with_capacity(frequencies.len() + 1);
+
+        bands.push(linkwitz_riley_lowpass(frequencies[0], sample_rate).to_vec());
+
+        for w in frequencies.windows(2) {
+            let mut cascade = linkwitz_riley_highpass(w[0], sample_rate).to_vec();
+            cascade.extend(linkwitz_riley_lowpass(w[1], sample_rate));
+            bands.push(cascade);
+        }
+
+        bands.push(linkwitz_riley_highpass(*frequencies.last().unwrap(), sample_rate).to_vec());
+
+        Ok(Crossover { bands })
+    }
+
+    /// Process `input` and return one filtered array per band, low to high.
+    fn process_audio(&mut self, py: Python<'_>, input: PyReadonlyArray1<f32>) -> PyResult<Vec<Py<PyArray1<f32>>>> {
+        let data = input.as_slice().unwrap();
+
+        let outputs = self
+            .bands
+            .iter_mut()
+            .map(|cascade| {
+                let band_output: Vec<f32> = data
+                    .iter()
+                    .map(|&sample| {
+                        cascade.iter_mut().fold(sample, |processed, filter| filter.process(processed))
+                    })
+                    .collect();
+                PyArray1::<f32>::from_slice_bound(py, &band_output).into()
+            })
+            .collect();
+
+        Ok(outputs)
+    }
+
+    fn reset(&mut self) {
+        for cascade in &mut self.bands {
+            for filter in cascade.iter_mut() {
+                filter.reset_state();
+            }
         }
-        self.set_gains(vec![0.0; self.gains.len()]);
     }
 }
 
 #[pymodule]
 fn native_dsp(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Equalizer>()?;
+    m.add_class::<Filter>()?;
+    m.add_class::<IIRFilter>()?;
+    m.add_class::<BiquadBank>()?;
+    m.add_class::<WeightingFilter>()?;
+    m.add_class::<Spl>()?;
+    m.add_class::<Crossover>()?;
     Ok(())
 }